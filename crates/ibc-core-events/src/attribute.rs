@@ -0,0 +1,89 @@
+//! Helpers shared by [`crate::decode`] for parsing ABCI event attributes.
+//!
+//! Attribute values are plain UTF-8 on current Tendermint/CometBFT, but some
+//! older Tendermint releases (and some JSON-RPC gateways in front of them)
+//! base64-encode every attribute value regardless of content. [`parse_attr`]
+//! tries the raw value first and only falls back to base64 when that parse
+//! fails. A base64-encoded value that also parses as given is therefore
+//! taken as given, undecoded; for a type whose `FromStr` accepts any string
+//! the base64 fallback is never reached.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+use crate::error::EventError;
+
+/// Returns the string value of `key` in `attrs` exactly as given; a
+/// base64-encoded value is returned undecoded.
+pub(crate) fn get_attr<'a>(
+    event_type: &str,
+    attrs: &'a [(String, String)],
+    key: &str,
+) -> Result<&'a str, EventError> {
+    attrs
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+        .ok_or_else(|| EventError::MissingKey {
+            event_type: event_type.to_string(),
+            key: key.to_string(),
+        })
+}
+
+/// Parses the string value of `key` in `attrs` as `T`, tolerating a
+/// base64-encoded value.
+pub(crate) fn parse_attr<T>(
+    event_type: &str,
+    attrs: &[(String, String)],
+    key: &str,
+) -> Result<T, EventError>
+where
+    T: FromStr,
+{
+    let raw = get_attr(event_type, attrs, key)?;
+
+    if let Ok(value) = raw.parse() {
+        return Ok(value);
+    }
+
+    decode_base64_str(raw)
+        .ok_or_else(|| EventError::InvalidAttributeEncoding {
+            event_type: event_type.to_string(),
+            key: key.to_string(),
+        })?
+        .parse()
+        .map_err(|_| EventError::ParseFailure {
+            event_type: event_type.to_string(),
+            key: key.to_string(),
+            reason: format!("could not parse `{raw}` as the expected type"),
+        })
+}
+
+fn decode_base64_str(raw: &str) -> Option<String> {
+    let decoded = BASE64.decode(raw).ok()?;
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_attr_accepts_plain_value() {
+        let attrs = alloc::vec![("packet_sequence".to_string(), "42".to_string())];
+        let sequence: u64 = parse_attr("send_packet", &attrs, "packet_sequence").unwrap();
+        assert_eq!(sequence, 42);
+    }
+
+    #[test]
+    fn parse_attr_falls_back_to_base64() {
+        let attrs = alloc::vec![("packet_sequence".to_string(), BASE64.encode("42"))];
+        let sequence: u64 = parse_attr("send_packet", &attrs, "packet_sequence").unwrap();
+        assert_eq!(sequence, 42);
+    }
+
+}
@@ -0,0 +1,400 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use ibc_core_channel_types::events::{
+    ChannelClosed, CloseConfirm as ChannelCloseConfirm, CloseInit as ChannelCloseInit,
+    OpenAck as ChannelOpenAck, OpenConfirm as ChannelOpenConfirm, OpenInit as ChannelOpenInit,
+    OpenTry as ChannelOpenTry,
+};
+use ibc_core_client_types::events::{
+    ClientMisbehaviour, CreateClient, UpdateClient, UpgradeClient, CLIENT_MISBEHAVIOUR_EVENT,
+    CREATE_CLIENT_EVENT, UPDATE_CLIENT_EVENT, UPGRADE_CLIENT_EVENT,
+};
+use ibc_core_connection_types::events::{
+    OpenAck as ConnectionOpenAck, OpenConfirm as ConnectionOpenConfirm,
+    OpenInit as ConnectionOpenInit, OpenTry as ConnectionOpenTry,
+};
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId};
+use ibc_core_router_types::event::{ModuleEvent, ModuleEventAttribute};
+use tendermint::abci;
+
+use crate::attribute::{get_attr, parse_attr};
+use crate::error::EventError;
+
+// Channel event type strings mirror the `event_type()` each struct below
+// reports; upstream keeps them file-private, so we restate them here rather
+// than widen their visibility in a crate we don't own.
+const CHANNEL_OPEN_INIT_EVENT: &str = "channel_open_init";
+const CHANNEL_OPEN_TRY_EVENT: &str = "channel_open_try";
+const CHANNEL_OPEN_ACK_EVENT: &str = "channel_open_ack";
+const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
+const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
+const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
+const CHANNEL_CLOSED_EVENT: &str = "channel_close";
+
+const CONNECTION_OPEN_INIT_EVENT: &str = "connection_open_init";
+const CONNECTION_OPEN_TRY_EVENT: &str = "connection_open_try";
+const CONNECTION_OPEN_ACK_EVENT: &str = "connection_open_ack";
+const CONNECTION_OPEN_CONFIRM_EVENT: &str = "connection_open_confirm";
+
+// Packet-carrying events (send/recv/write-ack/ack/timeout) additionally
+// require decoding `Packet`, `TimeoutHeight` and `Acknowledgement`, which
+// this crate doesn't yet do; see `decode_ibc_event` below.
+const SEND_PACKET_EVENT: &str = "send_packet";
+const RECEIVE_PACKET_EVENT: &str = "recv_packet";
+const WRITE_ACK_EVENT: &str = "write_acknowledgement";
+const ACK_PACKET_EVENT: &str = "acknowledge_packet";
+const TIMEOUT_EVENT: &str = "timeout_packet";
+
+const MESSAGE_EVENT: &str = "message";
+const MODULE_ATTRIBUTE_KEY: &str = "module";
+
+/// Decodes a `tendermint::abci::Event` into the [`IbcEvent`] it was encoded
+/// from. Attribute values parsed into typed fields accept base64 encoding in
+/// addition to the plain UTF-8 values current Tendermint/CometBFT emits,
+/// within the limits described in [`crate::attribute`]. The `module`
+/// attribute of a `message` event and the attributes of an event decoded to
+/// [`IbcEvent::Module`] are taken as given, without base64 decoding, and
+/// `create_client`/`update_client` events are passed unchanged to those
+/// types' own `TryFrom<abci::Event>` impls, so no base64 decoding happens
+/// here for them either.
+///
+/// Packet events (`send_packet`, `recv_packet`, `write_acknowledgement`,
+/// `acknowledge_packet`, `timeout_packet`) are not decoded: they return
+/// [`EventError::DecodeNotYetSupported`].
+///
+/// `ibc_core_handler_types::events::IbcEvent` only provides the opposite
+/// direction (`TryFrom<IbcEvent> for abci::Event`): both `IbcEvent` and
+/// `abci::Event` are foreign to this crate, so Rust's orphan rules rule out
+/// adding the missing `TryFrom<abci::Event> for IbcEvent` impl here. A free
+/// function is the closest equivalent a downstream crate can offer.
+///
+/// Any event whose `kind` isn't one of the well-known IBC event types above
+/// decodes to [`IbcEvent::Module`], matching how
+/// `ibc_core_handler_types::events::IbcEvent` itself treats events emitted
+/// by IBC applications rather than the core handler.
+pub fn decode_ibc_event(event: &abci::Event) -> Result<IbcEvent, EventError> {
+    let attrs = decoded_attrs(event)?;
+
+    Ok(match event.kind.as_str() {
+        CREATE_CLIENT_EVENT => IbcEvent::CreateClient(
+            CreateClient::try_from(event.clone())
+                .map_err(|e| parse_failure(CREATE_CLIENT_EVENT, e))?,
+        ),
+        UPDATE_CLIENT_EVENT => IbcEvent::UpdateClient(
+            UpdateClient::try_from(event.clone())
+                .map_err(|e| parse_failure(UPDATE_CLIENT_EVENT, e))?,
+        ),
+        UPGRADE_CLIENT_EVENT => IbcEvent::UpgradeClient(decode_upgrade_client(&attrs)?),
+        CLIENT_MISBEHAVIOUR_EVENT => {
+            IbcEvent::ClientMisbehaviour(decode_client_misbehaviour(&attrs)?)
+        }
+
+        CONNECTION_OPEN_INIT_EVENT => {
+            IbcEvent::OpenInitConnection(decode_connection_open_init(&attrs)?)
+        }
+        CONNECTION_OPEN_TRY_EVENT => {
+            IbcEvent::OpenTryConnection(decode_connection_open_try(&attrs)?)
+        }
+        CONNECTION_OPEN_ACK_EVENT => {
+            IbcEvent::OpenAckConnection(decode_connection_open_ack(&attrs)?)
+        }
+        CONNECTION_OPEN_CONFIRM_EVENT => {
+            IbcEvent::OpenConfirmConnection(decode_connection_open_confirm(&attrs)?)
+        }
+
+        CHANNEL_OPEN_INIT_EVENT => IbcEvent::OpenInitChannel(decode_channel_open_init(&attrs)?),
+        CHANNEL_OPEN_TRY_EVENT => IbcEvent::OpenTryChannel(decode_channel_open_try(&attrs)?),
+        CHANNEL_OPEN_ACK_EVENT => IbcEvent::OpenAckChannel(decode_channel_open_ack(&attrs)?),
+        CHANNEL_OPEN_CONFIRM_EVENT => {
+            IbcEvent::OpenConfirmChannel(decode_channel_open_confirm(&attrs)?)
+        }
+        CHANNEL_CLOSE_INIT_EVENT => IbcEvent::CloseInitChannel(decode_channel_close_init(&attrs)?),
+        CHANNEL_CLOSE_CONFIRM_EVENT => {
+            IbcEvent::CloseConfirmChannel(decode_channel_close_confirm(&attrs)?)
+        }
+        CHANNEL_CLOSED_EVENT => IbcEvent::ChannelClosed(decode_channel_closed(&attrs)?),
+
+        SEND_PACKET_EVENT | RECEIVE_PACKET_EVENT | WRITE_ACK_EVENT | ACK_PACKET_EVENT
+        | TIMEOUT_EVENT => {
+            return Err(EventError::DecodeNotYetSupported {
+                event_type: event.kind.clone(),
+            })
+        }
+
+        MESSAGE_EVENT => IbcEvent::Message(decode_message_event(&attrs)?),
+
+        _ => IbcEvent::Module(ModuleEvent {
+            kind: event.kind.clone(),
+            attributes: attrs.into_iter().map(ModuleEventAttribute::from).collect(),
+        }),
+    })
+}
+
+/// Extracts `(key, value)` pairs from an ABCI event's attributes.
+///
+/// `abci::EventAttribute::key_str`/`value_str` are fallible because the
+/// underlying bytes aren't guaranteed to be valid UTF-8; everything else in
+/// this crate works with plain `&str`/`String`, so we surface that failure
+/// once, here, rather than at every attribute access site.
+fn decoded_attrs(event: &abci::Event) -> Result<Vec<(String, String)>, EventError> {
+    event
+        .attributes
+        .iter()
+        .map(|attr| {
+            let key = attr
+                .key_str()
+                .map_err(|e| parse_failure(event.kind.as_str(), e))?
+                .to_string();
+            let value = attr
+                .value_str()
+                .map_err(|e| parse_failure(event.kind.as_str(), e))?
+                .to_string();
+            Ok((key, value))
+        })
+        .collect()
+}
+
+fn parse_failure<E: core::fmt::Display>(event_type: &str, error: E) -> EventError {
+    EventError::ParseFailure {
+        event_type: event_type.to_string(),
+        key: String::new(),
+        reason: error.to_string(),
+    }
+}
+
+fn decode_upgrade_client(attrs: &[(String, String)]) -> Result<UpgradeClient, EventError> {
+    Ok(UpgradeClient::new(
+        parse_attr(UPGRADE_CLIENT_EVENT, attrs, "client_id")?,
+        parse_attr(UPGRADE_CLIENT_EVENT, attrs, "client_type")?,
+        parse_attr(UPGRADE_CLIENT_EVENT, attrs, "consensus_height")?,
+    ))
+}
+
+fn decode_client_misbehaviour(
+    attrs: &[(String, String)],
+) -> Result<ClientMisbehaviour, EventError> {
+    Ok(ClientMisbehaviour::new(
+        parse_attr(CLIENT_MISBEHAVIOUR_EVENT, attrs, "client_id")?,
+        parse_attr(CLIENT_MISBEHAVIOUR_EVENT, attrs, "client_type")?,
+    ))
+}
+
+fn counterparty_connection_id(
+    event_type: &str,
+    attrs: &[(String, String)],
+) -> Result<Option<ConnectionId>, EventError> {
+    // Checked against the raw attribute, not a `parse_attr::<String>` result:
+    // `String`'s `FromStr` never fails, so going through `parse_attr` here
+    // would never exercise its base64 fallback.
+    if get_attr(event_type, attrs, "counterparty_connection_id")?.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(parse_attr(
+            event_type,
+            attrs,
+            "counterparty_connection_id",
+        )?))
+    }
+}
+
+fn decode_connection_open_init(
+    attrs: &[(String, String)],
+) -> Result<ConnectionOpenInit, EventError> {
+    Ok(ConnectionOpenInit::new(
+        parse_attr(CONNECTION_OPEN_INIT_EVENT, attrs, "connection_id")?,
+        parse_attr(CONNECTION_OPEN_INIT_EVENT, attrs, "client_id")?,
+        parse_attr(CONNECTION_OPEN_INIT_EVENT, attrs, "counterparty_client_id")?,
+    ))
+}
+
+fn decode_connection_open_try(
+    attrs: &[(String, String)],
+) -> Result<ConnectionOpenTry, EventError> {
+    let counterparty_connection_id =
+        counterparty_connection_id(CONNECTION_OPEN_TRY_EVENT, attrs)?.ok_or_else(|| {
+            EventError::MissingKey {
+                event_type: CONNECTION_OPEN_TRY_EVENT.to_string(),
+                key: "counterparty_connection_id".to_string(),
+            }
+        })?;
+
+    Ok(ConnectionOpenTry::new(
+        parse_attr(CONNECTION_OPEN_TRY_EVENT, attrs, "connection_id")?,
+        parse_attr(CONNECTION_OPEN_TRY_EVENT, attrs, "client_id")?,
+        counterparty_connection_id,
+        parse_attr(CONNECTION_OPEN_TRY_EVENT, attrs, "counterparty_client_id")?,
+    ))
+}
+
+fn decode_connection_open_ack(
+    attrs: &[(String, String)],
+) -> Result<ConnectionOpenAck, EventError> {
+    let counterparty_connection_id =
+        counterparty_connection_id(CONNECTION_OPEN_ACK_EVENT, attrs)?.ok_or_else(|| {
+            EventError::MissingKey {
+                event_type: CONNECTION_OPEN_ACK_EVENT.to_string(),
+                key: "counterparty_connection_id".to_string(),
+            }
+        })?;
+
+    Ok(ConnectionOpenAck::new(
+        parse_attr(CONNECTION_OPEN_ACK_EVENT, attrs, "connection_id")?,
+        parse_attr(CONNECTION_OPEN_ACK_EVENT, attrs, "client_id")?,
+        counterparty_connection_id,
+        parse_attr(CONNECTION_OPEN_ACK_EVENT, attrs, "counterparty_client_id")?,
+    ))
+}
+
+fn decode_connection_open_confirm(
+    attrs: &[(String, String)],
+) -> Result<ConnectionOpenConfirm, EventError> {
+    let counterparty_connection_id =
+        counterparty_connection_id(CONNECTION_OPEN_CONFIRM_EVENT, attrs)?.ok_or_else(|| {
+            EventError::MissingKey {
+                event_type: CONNECTION_OPEN_CONFIRM_EVENT.to_string(),
+                key: "counterparty_connection_id".to_string(),
+            }
+        })?;
+
+    Ok(ConnectionOpenConfirm::new(
+        parse_attr(CONNECTION_OPEN_CONFIRM_EVENT, attrs, "connection_id")?,
+        parse_attr(CONNECTION_OPEN_CONFIRM_EVENT, attrs, "client_id")?,
+        counterparty_connection_id,
+        parse_attr(CONNECTION_OPEN_CONFIRM_EVENT, attrs, "counterparty_client_id")?,
+    ))
+}
+
+fn counterparty_channel_id(
+    event_type: &str,
+    attrs: &[(String, String)],
+) -> Result<Option<ChannelId>, EventError> {
+    // See the comment on `counterparty_connection_id` above: the emptiness
+    // check has to run against the raw attribute, not a `parse_attr::<String>`
+    // result.
+    if get_attr(event_type, attrs, "counterparty_channel_id")?.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(parse_attr(
+            event_type,
+            attrs,
+            "counterparty_channel_id",
+        )?))
+    }
+}
+
+fn decode_channel_open_init(attrs: &[(String, String)]) -> Result<ChannelOpenInit, EventError> {
+    Ok(ChannelOpenInit::new(
+        parse_attr(CHANNEL_OPEN_INIT_EVENT, attrs, "port_id")?,
+        parse_attr(CHANNEL_OPEN_INIT_EVENT, attrs, "channel_id")?,
+        parse_attr(CHANNEL_OPEN_INIT_EVENT, attrs, "counterparty_port_id")?,
+        parse_attr(CHANNEL_OPEN_INIT_EVENT, attrs, "connection_id")?,
+        parse_attr(CHANNEL_OPEN_INIT_EVENT, attrs, "version")?,
+    ))
+}
+
+fn decode_channel_open_try(attrs: &[(String, String)]) -> Result<ChannelOpenTry, EventError> {
+    let counterparty_channel_id = counterparty_channel_id(CHANNEL_OPEN_TRY_EVENT, attrs)?
+        .ok_or_else(|| EventError::MissingKey {
+            event_type: CHANNEL_OPEN_TRY_EVENT.to_string(),
+            key: "counterparty_channel_id".to_string(),
+        })?;
+
+    Ok(ChannelOpenTry::new(
+        parse_attr(CHANNEL_OPEN_TRY_EVENT, attrs, "port_id")?,
+        parse_attr(CHANNEL_OPEN_TRY_EVENT, attrs, "channel_id")?,
+        parse_attr(CHANNEL_OPEN_TRY_EVENT, attrs, "counterparty_port_id")?,
+        counterparty_channel_id,
+        parse_attr(CHANNEL_OPEN_TRY_EVENT, attrs, "connection_id")?,
+        parse_attr(CHANNEL_OPEN_TRY_EVENT, attrs, "version")?,
+    ))
+}
+
+fn decode_channel_open_ack(attrs: &[(String, String)]) -> Result<ChannelOpenAck, EventError> {
+    let counterparty_channel_id = counterparty_channel_id(CHANNEL_OPEN_ACK_EVENT, attrs)?
+        .ok_or_else(|| EventError::MissingKey {
+            event_type: CHANNEL_OPEN_ACK_EVENT.to_string(),
+            key: "counterparty_channel_id".to_string(),
+        })?;
+
+    Ok(ChannelOpenAck::new(
+        parse_attr(CHANNEL_OPEN_ACK_EVENT, attrs, "port_id")?,
+        parse_attr(CHANNEL_OPEN_ACK_EVENT, attrs, "channel_id")?,
+        parse_attr(CHANNEL_OPEN_ACK_EVENT, attrs, "counterparty_port_id")?,
+        counterparty_channel_id,
+        parse_attr(CHANNEL_OPEN_ACK_EVENT, attrs, "connection_id")?,
+    ))
+}
+
+fn decode_channel_open_confirm(
+    attrs: &[(String, String)],
+) -> Result<ChannelOpenConfirm, EventError> {
+    let counterparty_channel_id = counterparty_channel_id(CHANNEL_OPEN_CONFIRM_EVENT, attrs)?
+        .ok_or_else(|| EventError::MissingKey {
+            event_type: CHANNEL_OPEN_CONFIRM_EVENT.to_string(),
+            key: "counterparty_channel_id".to_string(),
+        })?;
+
+    Ok(ChannelOpenConfirm::new(
+        parse_attr(CHANNEL_OPEN_CONFIRM_EVENT, attrs, "port_id")?,
+        parse_attr(CHANNEL_OPEN_CONFIRM_EVENT, attrs, "channel_id")?,
+        parse_attr(CHANNEL_OPEN_CONFIRM_EVENT, attrs, "counterparty_port_id")?,
+        counterparty_channel_id,
+        parse_attr(CHANNEL_OPEN_CONFIRM_EVENT, attrs, "connection_id")?,
+    ))
+}
+
+fn decode_channel_close_init(attrs: &[(String, String)]) -> Result<ChannelCloseInit, EventError> {
+    let counterparty_channel_id = counterparty_channel_id(CHANNEL_CLOSE_INIT_EVENT, attrs)?
+        .ok_or_else(|| EventError::MissingKey {
+            event_type: CHANNEL_CLOSE_INIT_EVENT.to_string(),
+            key: "counterparty_channel_id".to_string(),
+        })?;
+
+    Ok(ChannelCloseInit::new(
+        parse_attr(CHANNEL_CLOSE_INIT_EVENT, attrs, "port_id")?,
+        parse_attr(CHANNEL_CLOSE_INIT_EVENT, attrs, "channel_id")?,
+        parse_attr(CHANNEL_CLOSE_INIT_EVENT, attrs, "counterparty_port_id")?,
+        counterparty_channel_id,
+        parse_attr(CHANNEL_CLOSE_INIT_EVENT, attrs, "connection_id")?,
+    ))
+}
+
+fn decode_channel_close_confirm(
+    attrs: &[(String, String)],
+) -> Result<ChannelCloseConfirm, EventError> {
+    let counterparty_channel_id = counterparty_channel_id(CHANNEL_CLOSE_CONFIRM_EVENT, attrs)?
+        .ok_or_else(|| EventError::MissingKey {
+            event_type: CHANNEL_CLOSE_CONFIRM_EVENT.to_string(),
+            key: "counterparty_channel_id".to_string(),
+        })?;
+
+    Ok(ChannelCloseConfirm::new(
+        parse_attr(CHANNEL_CLOSE_CONFIRM_EVENT, attrs, "port_id")?,
+        parse_attr(CHANNEL_CLOSE_CONFIRM_EVENT, attrs, "channel_id")?,
+        parse_attr(CHANNEL_CLOSE_CONFIRM_EVENT, attrs, "counterparty_port_id")?,
+        counterparty_channel_id,
+        parse_attr(CHANNEL_CLOSE_CONFIRM_EVENT, attrs, "connection_id")?,
+    ))
+}
+
+fn decode_channel_closed(attrs: &[(String, String)]) -> Result<ChannelClosed, EventError> {
+    Ok(ChannelClosed::new(
+        parse_attr(CHANNEL_CLOSED_EVENT, attrs, "port_id")?,
+        parse_attr(CHANNEL_CLOSED_EVENT, attrs, "channel_id")?,
+        parse_attr(CHANNEL_CLOSED_EVENT, attrs, "counterparty_port_id")?,
+        counterparty_channel_id(CHANNEL_CLOSED_EVENT, attrs)?,
+        parse_attr(CHANNEL_CLOSED_EVENT, attrs, "connection_id")?,
+        parse_attr(CHANNEL_CLOSED_EVENT, attrs, "channel_ordering")?,
+    ))
+}
+
+fn decode_message_event(attrs: &[(String, String)]) -> Result<MessageEvent, EventError> {
+    match get_attr(MESSAGE_EVENT, attrs, MODULE_ATTRIBUTE_KEY)? {
+        "ibc_client" => Ok(MessageEvent::Client),
+        "ibc_connection" => Ok(MessageEvent::Connection),
+        "ibc_channel" => Ok(MessageEvent::Channel),
+        module => Ok(MessageEvent::Module(module.to_string())),
+    }
+}
@@ -0,0 +1,25 @@
+use alloc::string::String;
+
+use displaydoc::Display;
+
+/// Errors that can occur while converting between `IbcEvent` and
+/// `tendermint::abci::Event`.
+#[derive(Debug, Display)]
+pub enum EventError {
+    /// unknown event type `{event_type}`
+    UnknownEventType { event_type: String },
+    /// missing attribute `{key}` in event `{event_type}`
+    MissingKey { event_type: String, key: String },
+    /// attribute `{key}` in event `{event_type}` does not parse as given and is not base64-encoded utf-8
+    InvalidAttributeEncoding { event_type: String, key: String },
+    /// failed to parse attribute `{key}` in event `{event_type}`: {reason}
+    ParseFailure {
+        event_type: String,
+        key: String,
+        reason: String,
+    },
+    /// decoding `{event_type}` events back into `IbcEvent` is not yet supported
+    DecodeNotYetSupported { event_type: String },
+}
+
+impl core::error::Error for EventError {}
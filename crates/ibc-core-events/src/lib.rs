@@ -0,0 +1,30 @@
+//! Adds the one direction `ibc-core-handler-types` doesn't provide for its
+//! own [`IbcEvent`](ibc_core_handler_types::events::IbcEvent): decoding a
+//! `tendermint::abci::Event` back into the event it was encoded from.
+//! Upstream only goes one way (`IbcEvent` -> `abci::Event`), since that's
+//! all a host chain needs to emit events; a relayer or indexer reading them
+//! back off the chain needs the reverse, which is what [`decode_ibc_event`]
+//! provides.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
+#![deny(
+    warnings,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+extern crate alloc;
+
+mod attribute;
+mod decode;
+mod error;
+
+pub use decode::decode_ibc_event;
+pub use error::EventError;
+
+#[doc(inline)]
+pub use ibc_core_handler_types::events::IbcEvent;
@@ -36,6 +36,11 @@ pub mod connection {
     pub use ibc_core_connection::*;
 }
 
+pub mod events {
+    #[doc(inline)]
+    pub use ibc_core_events::*;
+}
+
 pub mod host {
     #[doc(inline)]
     pub use ibc_core_host::*;